@@ -1,4 +1,4 @@
-use rule_kit::{Rule, RuleEngine, RuleEngineBuilder};
+use rule_kit::{MutableRule, MutableRuleEngine, MutableRuleEngineBuilder};
 
 #[derive(Debug)]
 struct Order {
@@ -11,7 +11,7 @@ enum OrderRule {
     DiscountIfHighValue,
 }
 
-impl Rule<Order> for OrderRule {
+impl MutableRule<Order> for OrderRule {
     type RuleError = ();
 
     fn name(&self) -> &str {
@@ -59,10 +59,10 @@ fn main() {
 
     let rules = vec![OrderRule::DiscountIfHighValue];
 
-    // Using RuleEngine directly; pass mutable reference to context
-    let mut engine = RuleEngine::new(rules.clone(), None);
-    engine.evaluate_all(&mut order).unwrap();
-    println!("Discount after RuleEngine: {:.2}", order.discount);
+    // Using MutableRuleEngine directly; pass mutable reference to context
+    let mut engine = MutableRuleEngine::new(rules.clone(), None);
+    engine.evaluate_all_mut(&mut order).unwrap();
+    println!("Discount after MutableRuleEngine: {:.2}", order.discount);
 
     // Using builder (with priority); also requires mutable context
     let mut order2 = Order {
@@ -70,12 +70,12 @@ fn main() {
         discount: 0.0,
     };
 
-    let mut engine_built = RuleEngineBuilder::new()
+    let mut engine_built = MutableRuleEngineBuilder::new()
         .with_rules(rules)
         .priority_asc()
         .build();
 
-    engine_built.evaluate_all(&mut order2).unwrap();
-    println!("Discount after RuleEngineBuilder: {:.2}", order2.discount);
-    println!("Total after RuleEngineBuilder: {:.2}", order2.total);
-}
\ No newline at end of file
+    engine_built.evaluate_all_mut(&mut order2).unwrap();
+    println!("Discount after MutableRuleEngineBuilder: {:.2}", order2.discount);
+    println!("Total after MutableRuleEngineBuilder: {:.2}", order2.total);
+}
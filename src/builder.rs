@@ -1,7 +1,59 @@
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use crate::RuleEngine;
+use crate::MutableRuleEngine;
 use crate::PriorityOrder;
 use crate::Rule;
+use crate::MutableRule;
+use crate::error::RuleSetError;
+
+/// A custom ordering comparator set via [`RuleEngineBuilder::order_by`].
+type RuleComparator<R> = Box<dyn Fn(&R, &R) -> std::cmp::Ordering>;
+
+/// A named, reusable bundle of rules that can be registered with a
+/// [`RuleEngineBuilder`] and activated by name via
+/// [`RuleEngineBuilder::with_rule_set`] / [`RuleEngineBuilder::with_rule_sets`].
+///
+/// Modeled on how `conjure-oxide` resolves rules: libraries ship `RuleSet`s,
+/// and applications compose an engine declaratively by naming which sets to
+/// activate instead of pushing every rule by hand.
+pub struct RuleSet<C, R> {
+    /// The set's name, used to activate it from the builder.
+    pub name: String,
+
+    /// The set's priority, used to order it relative to other sets
+    /// activated together (lower values resolve first).
+    pub priority: u32,
+
+    /// The rules belonging to this set.
+    pub rules: Vec<R>,
+
+    /// Names of other registered sets that must be activated whenever this
+    /// one is, pulled in transitively.
+    pub depends_on: Vec<String>,
+
+    marker: PhantomData<C>,
+}
+
+impl<C, R> RuleSet<C, R> {
+    /// Creates a new rule set with no dependencies.
+    pub fn new(name: impl Into<String>, priority: u32, rules: Vec<R>) -> Self {
+        Self {
+            name: name.into(),
+            priority,
+            rules,
+            depends_on: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Declares that this set depends on the named sets, which will be
+    /// activated transitively whenever this one is.
+    pub fn depends_on(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.depends_on = names.into_iter().map(Into::into).collect();
+        self
+    }
+}
 
 /// A builder for constructing a [`RuleEngine`] with a fluent interface.
 ///
@@ -21,6 +73,14 @@ pub struct RuleEngineBuilder<C, R> {
 
     /// Marker to track the context type.
     pub marker: PhantomData<C>,
+
+    /// Rule sets registered via [`RuleEngineBuilder::register_rule_set`],
+    /// available to be activated by name.
+    pub rule_sets: HashMap<String, RuleSet<C, R>>,
+
+    /// A custom comparator set via [`RuleEngineBuilder::order_by`], used in
+    /// place of `order` when present.
+    pub comparator: Option<RuleComparator<R>>,
 }
 
 impl<C, R> RuleEngineBuilder<C, R>
@@ -33,6 +93,8 @@ where
             rules: Vec::new(),
             order: PriorityOrder::default(),
             marker: PhantomData,
+            rule_sets: HashMap::new(),
+            comparator: None,
         }
     }
 
@@ -64,11 +126,45 @@ where
         self.priority(PriorityOrder::Asc)
     }
 
+    /// Orders by priority (per the `Asc`/`Desc` preset set via
+    /// [`RuleEngineBuilder::priority`]), then breaks ties by
+    /// [`Rule::name`], so that two builders registering the same rules in a
+    /// different order still produce an identically-ordered engine.
+    pub fn order_by_priority_then_name(self) -> Self {
+        let order = self.order;
+        self.order_by(move |a, b| {
+            let primary = match order {
+                PriorityOrder::Asc => a.priority().cmp(&b.priority()),
+                PriorityOrder::Desc => b.priority().cmp(&a.priority()),
+            };
+            primary.then_with(|| a.name().cmp(b.name()))
+        })
+    }
+
+    /// Sets a custom comparator used to order rules in [`RuleEngineBuilder::build`],
+    /// in place of the `Asc`/`Desc` presets. Ties (and the overall order)
+    /// are whatever `cmp` decides, unlocking secondary sort keys such as
+    /// "priority, then rule name, then registration order" without
+    /// encoding everything into a single integer.
+    pub fn order_by(mut self, cmp: impl Fn(&R, &R) -> std::cmp::Ordering + 'static) -> Self {
+        self.comparator = Some(Box::new(cmp));
+        self
+    }
+
     /// Builds the final [`RuleEngine`] with sorted rules.
+    ///
+    /// If [`RuleEngineBuilder::order_by`] was called, that comparator is
+    /// used. Otherwise rules are sorted by `priority()` per the `Asc`/`Desc`
+    /// preset set via [`RuleEngineBuilder::priority`]. Either way the sort
+    /// is stable, so rules that compare equal (equal priority, for the
+    /// presets) keep their registration order.
     pub fn build(mut self) -> RuleEngine<C, R> {
-        match self.order {
-            PriorityOrder::Asc => self.rules.sort_by_key(|r| r.priority()),
-            PriorityOrder::Desc => self.rules.sort_by_key(|r| std::cmp::Reverse(r.priority())),
+        match self.comparator.take() {
+            Some(cmp) => self.rules.sort_by(|a, b| cmp(a, b)),
+            None => match self.order {
+                PriorityOrder::Asc => self.rules.sort_by_key(|r| r.priority()),
+                PriorityOrder::Desc => self.rules.sort_by_key(|r| std::cmp::Reverse(r.priority())),
+            },
         }
 
         RuleEngine {
@@ -77,4 +173,169 @@ where
             _marker: PhantomData,
         }
     }
+
+    /// Builds a [`crate::engine::BucketedRuleEngine`] instead of a sorted
+    /// [`RuleEngine`], indexing rules directly by priority. Use this when
+    /// `priority()` returns a small, bounded integer and a full comparison
+    /// sort is overkill; see [`crate::engine::BucketedRuleEngine`].
+    pub fn build_bucketed(self, max_priority: u32) -> crate::engine::BucketedRuleEngine<C, R> {
+        crate::engine::BucketedRuleEngine::new(self.rules, max_priority, Some(self.order))
+    }
+
+    /// Builds a [`crate::priority_queue::PriorityRuleQueue`] instead of a
+    /// sorted [`RuleEngine`], keyed by each rule's position in registration
+    /// order. Use this when priorities need to change at runtime (e.g.
+    /// demoting a rule that keeps failing) without rebuilding the engine;
+    /// see [`crate::priority_queue::PriorityRuleQueue`].
+    pub fn build_indexed(self) -> crate::priority_queue::PriorityRuleQueue<usize, C, R> {
+        let mut queue = crate::priority_queue::PriorityRuleQueue::new(self.order);
+        for (id, rule) in self.rules.into_iter().enumerate() {
+            queue.insert(id, rule);
+        }
+        queue
+    }
+}
+
+impl<C, R> RuleEngineBuilder<C, R>
+where
+    R: Rule<C> + Clone + PartialEq,
+{
+    /// Registers a named rule set so it can later be activated via
+    /// [`RuleEngineBuilder::with_rule_set`] or [`RuleEngineBuilder::with_rule_sets`].
+    pub fn register_rule_set(mut self, set: RuleSet<C, R>) -> Self {
+        self.rule_sets.insert(set.name.clone(), set);
+        self
+    }
+
+    /// Activates a single registered rule set by name, pulling in its
+    /// transitive dependencies. See [`RuleEngineBuilder::with_rule_sets`].
+    pub fn with_rule_set(self, name: &str) -> Result<Self, RuleSetError> {
+        self.with_rule_sets(&[name])
+    }
+
+    /// Activates several registered rule sets by name, pulling in their
+    /// transitive dependencies, ordering the resolved sets by
+    /// [`RuleSet::priority`], and unioning their rules (de-duplicated) into
+    /// the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleSetError::RuleSetNotFound` if `names`, or a dependency
+    /// reachable from them, names a set that hasn't been registered.
+    pub fn with_rule_sets(mut self, names: &[&str]) -> Result<Self, RuleSetError> {
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+
+        for name in names {
+            self.resolve_rule_set(name, &mut visited, &mut order)?;
+        }
+
+        let mut sets: Vec<&RuleSet<C, R>> = order.iter().map(|name| &self.rule_sets[name]).collect();
+        sets.sort_by_key(|set| set.priority);
+
+        let resolved_rules: Vec<R> = sets
+            .into_iter()
+            .flat_map(|set| set.rules.iter().cloned())
+            .collect();
+
+        for rule in resolved_rules {
+            if !self.rules.contains(&rule) {
+                self.rules.push(rule);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Depth-first resolves `name` and its transitive dependencies into
+    /// `order`, visiting each set name at most once.
+    fn resolve_rule_set(
+        &self,
+        name: &str,
+        visited: &mut HashSet<String>,
+        order: &mut Vec<String>,
+    ) -> Result<(), RuleSetError> {
+        if !visited.insert(name.to_string()) {
+            return Ok(());
+        }
+
+        let set = self
+            .rule_sets
+            .get(name)
+            .ok_or_else(|| RuleSetError::RuleSetNotFound(name.to_string()))?;
+
+        for dep in &set.depends_on {
+            self.resolve_rule_set(dep, visited, order)?;
+        }
+
+        order.push(name.to_string());
+        Ok(())
+    }
+}
+
+/// A builder for constructing a [`MutableRuleEngine`] with a fluent interface.
+///
+/// Mirrors [`RuleEngineBuilder`], but for [`MutableRule`] implementors.
+///
+/// # Type Parameters
+///
+/// * `C` - The mutable context type used by the rules.
+/// * `R` - A type that implements the [`MutableRule`] trait for context `C`.
+#[derive(Default)]
+pub struct MutableRuleEngineBuilder<C, R> {
+    /// Rules to be added into the rule engine.
+    pub rules: Vec<R>,
+
+    /// Evaluation order (ascending or descending).
+    pub order: PriorityOrder,
+
+    /// Marker to track the context type.
+    pub marker: PhantomData<C>,
+}
+
+impl<C, R> MutableRuleEngineBuilder<C, R>
+where
+    R: MutableRule<C>,
+{
+    /// Creates a new, empty builder with default ascending priority.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            order: PriorityOrder::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the full list of rules.
+    pub fn with_rules(mut self, rules: Vec<R>) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Adds a single rule to the existing list.
+    pub fn add_rule(mut self, rule: R) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Sets evaluation priority order explicitly.
+    pub fn priority(mut self, order: PriorityOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets priority to descending (highest first).
+    pub fn priority_desc(self) -> Self {
+        self.priority(PriorityOrder::Desc)
+    }
+
+    /// Sets priority to ascending (lowest first).
+    pub fn priority_asc(self) -> Self {
+        self.priority(PriorityOrder::Asc)
+    }
+
+    /// Builds the final [`MutableRuleEngine`] with sorted rules.
+    pub fn build(self) -> MutableRuleEngine<C, R> {
+        MutableRuleEngine::new(self.rules, Some(self.order))
+    }
 }
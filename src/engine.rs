@@ -1,7 +1,22 @@
 use std::marker::PhantomData;
 use crate::error::RuleEngineError;
 use crate::traits::Rule;
-use crate::utils::{PriorityOrder};
+use crate::utils::{PriorityOrder, RuleOutcome, RuleRecord, RuleReport, TraceEntry, TraceEvaluation};
+
+/// Return type of [`RuleEngine::evaluate_all_traced`]: the same result
+/// [`RuleEngine::evaluate_all`] would return, paired with a [`TraceEntry`]
+/// for every rule considered.
+type TracedAllResult<O, E> = (Result<Vec<O>, RuleEngineError<E>>, Vec<TraceEntry>);
+
+/// Return type of [`RuleEngine::evaluate_first_traced`]: the same result
+/// [`RuleEngine::evaluate_first`] would return, paired with a [`TraceEntry`]
+/// for every rule considered up to the stopping point.
+type TracedFirstResult<O, E> = (Result<Option<O>, RuleEngineError<E>>, Vec<TraceEntry>);
+
+/// Per-rule outcome collected by [`RuleEngine::evaluate_all_par`]: `Some`
+/// output if the rule fired, `None` if it didn't apply.
+#[cfg(feature = "rayon")]
+type RuleOutcomeResult<O, E> = Result<Option<O>, RuleEngineError<E>>;
 
 /// A generic rule engine that evaluates and applies a list of rules based on a given context.
 ///
@@ -102,6 +117,264 @@ where
         }
         Ok(None)
     }
+
+    /// Runs every rule against `ctx` and returns an aggregated
+    /// [`RuleReport`] describing what happened, instead of stopping at the
+    /// first error.
+    ///
+    /// Unlike [`RuleEngine::evaluate_all`], this never short-circuits: a
+    /// rule that errors while being classified or applied is simply
+    /// recorded with `outcome: None` and counted in `errored`, so a batch
+    /// audit over hundreds of rules can run to completion and be logged as
+    /// a single pass/skip/undetermined/error tally (see [`RuleReport::summary`]).
+    pub fn evaluate_report(&self, ctx: &C) -> RuleReport<R::Output> {
+        let mut records = Vec::with_capacity(self._rules.len());
+        let mut applied = 0;
+        let mut skipped = 0;
+        let mut undetermined = 0;
+        let mut errored = 0;
+
+        for rule in &self._rules {
+            let name = rule.name().to_string();
+
+            match rule.classify(ctx) {
+                Ok(RuleOutcome::Applied) => match rule.apply(ctx) {
+                    Ok(output) => {
+                        applied += 1;
+                        records.push(RuleRecord {
+                            name,
+                            outcome: Some(RuleOutcome::Applied),
+                            output: Some(output),
+                        });
+                    }
+                    Err(_) => {
+                        errored += 1;
+                        records.push(RuleRecord {
+                            name,
+                            outcome: None,
+                            output: None,
+                        });
+                    }
+                },
+                Ok(outcome @ RuleOutcome::Skipped) => {
+                    skipped += 1;
+                    records.push(RuleRecord {
+                        name,
+                        outcome: Some(outcome),
+                        output: None,
+                    });
+                }
+                Ok(outcome @ RuleOutcome::Undetermined) => {
+                    undetermined += 1;
+                    records.push(RuleRecord {
+                        name,
+                        outcome: Some(outcome),
+                        output: None,
+                    });
+                }
+                Err(_) => {
+                    errored += 1;
+                    records.push(RuleRecord {
+                        name,
+                        outcome: None,
+                        output: None,
+                    });
+                }
+            }
+        }
+
+        RuleReport {
+            records,
+            applied,
+            skipped,
+            undetermined,
+            errored,
+        }
+    }
+
+    /// Like [`RuleEngine::evaluate_all`], but additionally returns a
+    /// [`TraceEntry`] for every rule considered, recording its name,
+    /// priority, what `evaluate` returned, and whether `apply` ran and
+    /// succeeded.
+    ///
+    /// This mirrors `evaluate_all`'s short-circuit-on-error semantics
+    /// exactly; tracing is purely additive, so `evaluate_all` remains the
+    /// zero-overhead path when a trace isn't needed.
+    pub fn evaluate_all_traced(&self, ctx: &C) -> TracedAllResult<R::Output, R::RuleError> {
+        let mut results = Vec::new();
+        let mut trace = Vec::new();
+
+        for rule in &self._rules {
+            let rule_name = rule.name().to_string();
+            let priority = rule.priority();
+
+            match rule.evaluate(ctx) {
+                Ok(true) => match rule.apply(ctx) {
+                    Ok(out) => {
+                        trace.push(TraceEntry {
+                            rule_name,
+                            priority,
+                            evaluation: TraceEvaluation::True,
+                            applied: Some(true),
+                        });
+                        results.push(out);
+                    }
+                    Err(e) => {
+                        trace.push(TraceEntry {
+                            rule_name,
+                            priority,
+                            evaluation: TraceEvaluation::True,
+                            applied: Some(false),
+                        });
+                        return (Err(RuleEngineError::Application(e)), trace);
+                    }
+                },
+                Ok(false) => {
+                    trace.push(TraceEntry {
+                        rule_name,
+                        priority,
+                        evaluation: TraceEvaluation::False,
+                        applied: None,
+                    });
+                }
+                Err(e) => {
+                    trace.push(TraceEntry {
+                        rule_name,
+                        priority,
+                        evaluation: TraceEvaluation::Errored,
+                        applied: None,
+                    });
+                    return (Err(RuleEngineError::Evaluation(e)), trace);
+                }
+            }
+        }
+
+        (Ok(results), trace)
+    }
+
+    /// Like [`RuleEngine::evaluate_first`], but additionally returns a
+    /// [`TraceEntry`] for every rule considered up to and including the one
+    /// `evaluate_first` stopped at.
+    pub fn evaluate_first_traced(&self, ctx: &C) -> TracedFirstResult<R::Output, R::RuleError> {
+        let mut trace = Vec::new();
+
+        for rule in &self._rules {
+            let rule_name = rule.name().to_string();
+            let priority = rule.priority();
+
+            match rule.evaluate(ctx) {
+                Ok(true) => {
+                    return match rule.apply(ctx) {
+                        Ok(out) => {
+                            trace.push(TraceEntry {
+                                rule_name,
+                                priority,
+                                evaluation: TraceEvaluation::True,
+                                applied: Some(true),
+                            });
+                            (Ok(Some(out)), trace)
+                        }
+                        Err(e) => {
+                            trace.push(TraceEntry {
+                                rule_name,
+                                priority,
+                                evaluation: TraceEvaluation::True,
+                                applied: Some(false),
+                            });
+                            (Err(RuleEngineError::Application(e)), trace)
+                        }
+                    };
+                }
+                Ok(false) => {
+                    trace.push(TraceEntry {
+                        rule_name,
+                        priority,
+                        evaluation: TraceEvaluation::False,
+                        applied: None,
+                    });
+                }
+                Err(e) => {
+                    trace.push(TraceEntry {
+                        rule_name,
+                        priority,
+                        evaluation: TraceEvaluation::Errored,
+                        applied: None,
+                    });
+                    return (Err(RuleEngineError::Evaluation(e)), trace);
+                }
+            }
+        }
+
+        (Ok(None), trace)
+    }
+}
+
+/// Parallel evaluation for stateless rule engines, via Rayon.
+///
+/// Gated behind the `rayon` feature since it pulls in the `rayon` crate and
+/// requires `R`/`C`/`R::Output`/`R::RuleError` to be `Sync`/`Send`.
+#[cfg(feature = "rayon")]
+impl<C, R> RuleEngine<C, R>
+where
+    R: Rule<C> + Sync,
+    C: Sync,
+    R::Output: Send,
+    R::RuleError: Send,
+{
+    /// Evaluates and applies independent rules in parallel using Rayon.
+    ///
+    /// Rules are grouped by priority (the groups the sequential path would
+    /// visit one at a time); each group is evaluated concurrently with
+    /// `par_iter`, but outputs are collected back into the group's original
+    /// index order, and groups themselves are processed in priority order.
+    /// This keeps observable output ordering identical to [`RuleEngine::evaluate_all`]
+    /// while evaluating within a priority group concurrently.
+    ///
+    /// On error, the lowest-index error is returned deterministically,
+    /// regardless of which thread finishes first.
+    pub fn evaluate_all_par(&self, ctx: &C) -> Result<Vec<R::Output>, RuleEngineError<R::RuleError>> {
+        use rayon::prelude::*;
+
+        let mut results = Vec::new();
+
+        for group in self.priority_groups() {
+            let outcomes: Vec<RuleOutcomeResult<R::Output, R::RuleError>> = group
+                .par_iter()
+                .map(|rule| {
+                    if rule.evaluate(ctx).map_err(RuleEngineError::Evaluation)? {
+                        rule.apply(ctx).map(Some).map_err(RuleEngineError::Application)
+                    } else {
+                        Ok(None)
+                    }
+                })
+                .collect();
+
+            for outcome in outcomes {
+                if let Some(out) = outcome? {
+                    results.push(out);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Splits `self._rules` (already sorted per `self._order`) into
+    /// consecutive slices that share the same priority, preserving overall
+    /// order.
+    fn priority_groups(&self) -> Vec<&[R]> {
+        let mut groups = Vec::new();
+        let mut start = 0;
+
+        for i in 1..=self._rules.len() {
+            if i == self._rules.len() || self._rules[i].priority() != self._rules[start].priority() {
+                groups.push(&self._rules[start..i]);
+                start = i;
+            }
+        }
+
+        groups
+    }
 }
 
 use crate::traits::MutableRule;
@@ -178,4 +451,330 @@ where
         }
         Ok(false)
     }
+
+    /// Repeatedly evaluates the rule set against `ctx` until a fixpoint is
+    /// reached, firing each rule at most once (forward-chaining saturation).
+    ///
+    /// Each round scans the rules that have not yet fired, in priority order,
+    /// and applies every one whose [`MutableRule::evaluate`] returns `true`.
+    /// Rounds repeat as long as at least one rule fired during the previous
+    /// round, since a rule's mutation may make the condition of a rule that
+    /// was already passed over in that round become true (e.g. rule A sets a
+    /// flag that rule B checks, but B comes before A in priority order).
+    ///
+    /// Evaluation stops as soon as a round fires nothing. The names of the
+    /// rules that never became applicable are returned so callers can tell
+    /// saturation from partial coverage.
+    ///
+    /// # Errors
+    ///
+    /// * `RuleEngineError::Evaluation` / `RuleEngineError::Application` - if a rule fails.
+    /// * `RuleEngineError::Cycle` - if no fixpoint is reached within `max_rounds`.
+    ///   Since each rule fires at most once, `N` rules never need more than
+    ///   `N + 1` rounds, so this only happens when `max_rounds` is smaller.
+    pub fn evaluate_saturate(
+        &mut self,
+        ctx: &mut C,
+        max_rounds: usize,
+    ) -> Result<Vec<String>, RuleEngineError<R::RuleError>> {
+        let mut pending: Vec<usize> = (0..self._rules.len()).collect();
+        let mut rounds = 0usize;
+
+        loop {
+            if pending.is_empty() {
+                return Ok(Vec::new());
+            }
+            if rounds >= max_rounds {
+                return Err(RuleEngineError::Cycle { rounds });
+            }
+            rounds += 1;
+
+            let mut still_pending = Vec::new();
+            let mut progressed = false;
+
+            for idx in pending {
+                let rule = &mut self._rules[idx];
+                if rule.evaluate(ctx).map_err(RuleEngineError::Evaluation)? {
+                    rule.before_apply(ctx);
+                    rule.apply(ctx).map_err(RuleEngineError::Application)?;
+                    rule.after_apply(ctx);
+                    progressed = true;
+                } else {
+                    still_pending.push(idx);
+                }
+            }
+
+            pending = still_pending;
+
+            if !progressed {
+                return Ok(pending
+                    .into_iter()
+                    .map(|idx| self._rules[idx].name().to_string())
+                    .collect());
+            }
+        }
+    }
+}
+
+/// A rule engine that indexes rules directly by priority in a bucket array
+/// instead of sorting them, via [`crate::builder::RuleEngineBuilder::build_bucketed`].
+///
+/// Suitable when `priority()` returns a small, bounded integer: enqueueing
+/// is `O(1)` per rule and draining is `O(range)` instead of `O(N log N)`,
+/// which wins when `N` is large and the priority range is small. Presents
+/// the same `evaluate_all`/`evaluate_first` API as [`RuleEngine`] so callers
+/// can switch transparently.
+#[derive(Debug)]
+pub struct BucketedRuleEngine<C, R> {
+    /// Rules bucketed by priority: `_buckets[p]` holds every rule whose
+    /// `priority()` is `p` (clamped to the configured `max_priority`).
+    pub _buckets: Vec<Vec<R>>,
+
+    /// Determines whether buckets are scanned in ascending or descending order.
+    pub _order: PriorityOrder,
+
+    /// Phantom marker to associate the context type `C` without storing it.
+    pub _marker: PhantomData<C>,
+}
+
+impl<C, R> BucketedRuleEngine<C, R>
+where
+    R: Rule<C>,
+{
+    /// Builds a bucketed engine from `rules`, indexing them by
+    /// `priority()` into `max_priority + 1` buckets. Priorities above
+    /// `max_priority` are clamped into the last bucket.
+    pub fn new(rules: Vec<R>, max_priority: u32, order: Option<PriorityOrder>) -> Self {
+        let order = order.unwrap_or_default();
+        let mut buckets: Vec<Vec<R>> = (0..=max_priority).map(|_| Vec::new()).collect();
+
+        for rule in rules {
+            let bucket = (rule.priority() as usize).min(max_priority as usize);
+            buckets[bucket].push(rule);
+        }
+
+        Self {
+            _buckets: buckets,
+            _order: order,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Yields rules bucket by bucket, scanning ascending or descending per `_order`.
+    fn iter_rules(&self) -> impl Iterator<Item = &R> {
+        let indices: Box<dyn Iterator<Item = usize>> = match self._order {
+            PriorityOrder::Asc => Box::new(0..self._buckets.len()),
+            PriorityOrder::Desc => Box::new((0..self._buckets.len()).rev()),
+        };
+        indices.flat_map(move |i| self._buckets[i].iter())
+    }
+
+    /// Evaluates all rules and applies those that return `true` from [`Rule::evaluate`].
+    /// See [`RuleEngine::evaluate_all`].
+    pub fn evaluate_all(&self, ctx: &C) -> Result<Vec<R::Output>, RuleEngineError<R::RuleError>> {
+        let mut results = Vec::new();
+
+        for rule in self.iter_rules() {
+            if rule.evaluate(ctx).map_err(RuleEngineError::Evaluation)? {
+                let out = rule.apply(ctx).map_err(RuleEngineError::Application)?;
+                results.push(out);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Evaluates rules in bucket order and returns the output of the first
+    /// rule that applies. See [`RuleEngine::evaluate_first`].
+    pub fn evaluate_first(&self, ctx: &C) -> Result<Option<R::Output>, RuleEngineError<R::RuleError>> {
+        for rule in self.iter_rules() {
+            if rule.evaluate(ctx).map_err(RuleEngineError::Evaluation)? {
+                return rule
+                    .apply(ctx)
+                    .map(Some)
+                    .map_err(RuleEngineError::Application);
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// One step completed by the scheduler inside
+/// [`RuleEngine::evaluate_concurrent`]: either a rule's `evaluate` finished,
+/// or (for a rule whose `evaluate` returned `true`) its `apply` finished.
+/// The weight/group stay attached to the step so the budget can be released
+/// only once the rule's whole lifecycle (evaluate *and*, if applicable,
+/// apply) has completed.
+#[cfg(feature = "async")]
+enum ConcurrentStep<'a, R, O, E> {
+    Evaluated {
+        rule: &'a R,
+        weight: u32,
+        group: Option<String>,
+        result: Result<bool, E>,
+    },
+    Applied {
+        weight: u32,
+        group: Option<String>,
+        result: Result<O, E>,
+    },
+}
+
+/// Async concurrent evaluation for rules that perform I/O, via
+/// [`crate::traits::AsyncRule`].
+///
+/// Gated behind the `async` feature since it pulls in `futures`/`async_trait`.
+#[cfg(feature = "async")]
+impl<C, R> RuleEngine<C, R>
+where
+    R: crate::traits::AsyncRule<C>,
+    C: Sync,
+{
+    /// Evaluates rules concurrently while respecting a global in-flight
+    /// weight budget and optional per-group weight budgets.
+    ///
+    /// Rules are still *started* in priority order (per the engine's
+    /// configured [`PriorityOrder`]): a rule only begins once both the
+    /// global budget and its group's budget (if any) have enough headroom
+    /// for its `weight()`. A rule's weight stays reserved for its entire
+    /// lifecycle — both `evaluate` and, if it fires, `apply` — and `apply`
+    /// itself runs in the same concurrent pool as other rules' `evaluate`/
+    /// `apply`, so two I/O-bound calls can genuinely overlap rather than
+    /// the whole scheduler blocking on one rule's `apply`.
+    ///
+    /// A group absent from `group_limits` is only bound by the global budget.
+    ///
+    /// Takes `ctx: &C` rather than `&mut C`: rules run concurrently here, so
+    /// a shared mutable context isn't available the way it is for
+    /// [`MutableRuleEngine`] — this is meant for stateless, I/O-bound rules
+    /// ([`crate::traits::AsyncRule`] takes `&C` for the same reason) that
+    /// report their effects through `Output` instead of mutating `ctx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RuleEngineError::WeightExceedsBudget` up front if any
+    /// rule's `weight()` exceeds `global_limit` or its group's limit — such
+    /// a rule could never be scheduled, so this is reported instead of
+    /// silently never running it. Otherwise returns the first
+    /// `RuleEngineError::Evaluation`/`RuleEngineError::Application`
+    /// encountered, in completion order.
+    pub async fn evaluate_concurrent(
+        &self,
+        ctx: &C,
+        global_limit: u32,
+        group_limits: &std::collections::HashMap<String, u32>,
+    ) -> Result<Vec<R::Output>, RuleEngineError<R::RuleError>> {
+        use futures::stream::FuturesUnordered;
+        use futures::{FutureExt, StreamExt};
+        use std::collections::{HashMap, VecDeque};
+
+        for rule in &self._rules {
+            let weight = rule.weight();
+            if weight > global_limit {
+                return Err(RuleEngineError::WeightExceedsBudget {
+                    rule_name: rule.name().to_string(),
+                    weight,
+                    limit: global_limit,
+                });
+            }
+            if let Some(&cap) = rule.group().and_then(|g| group_limits.get(g)) {
+                if weight > cap {
+                    return Err(RuleEngineError::WeightExceedsBudget {
+                        rule_name: rule.name().to_string(),
+                        weight,
+                        limit: cap,
+                    });
+                }
+            }
+        }
+
+        let mut ordered: Vec<&R> = self._rules.iter().collect();
+        match self._order {
+            PriorityOrder::Asc => ordered.sort_by_key(|r| r.priority()),
+            PriorityOrder::Desc => ordered.sort_by_key(|r| std::cmp::Reverse(r.priority())),
+        }
+        let mut pending: VecDeque<&R> = ordered.into();
+
+        let mut running_global = 0u32;
+        let mut running_group: HashMap<String, u32> = HashMap::new();
+        let mut in_flight: FuturesUnordered<_> = FuturesUnordered::new();
+        let mut results = Vec::new();
+
+        loop {
+            while let Some(rule) = pending.front() {
+                let weight = rule.weight();
+                let group = rule.group().map(str::to_string);
+
+                let global_ok = running_global + weight <= global_limit;
+                let group_ok = match &group {
+                    Some(g) => match group_limits.get(g) {
+                        Some(&cap) => running_group.get(g).copied().unwrap_or(0) + weight <= cap,
+                        None => true,
+                    },
+                    None => true,
+                };
+
+                if !(global_ok && group_ok) {
+                    break;
+                }
+
+                let rule = pending.pop_front().unwrap();
+                running_global += weight;
+                if let Some(g) = &group {
+                    *running_group.entry(g.clone()).or_insert(0) += weight;
+                }
+
+                in_flight.push(
+                    async move {
+                        let result = rule.evaluate(ctx).await;
+                        ConcurrentStep::Evaluated { rule, weight, group, result }
+                    }
+                    .boxed(),
+                );
+            }
+
+            let Some(step) = in_flight.next().await else {
+                break;
+            };
+
+            match step {
+                ConcurrentStep::Evaluated { rule, weight, group, result } => match result {
+                    Ok(true) => {
+                        // Keep the weight reserved and run `apply` in the same
+                        // pool so it can overlap with other rules in flight.
+                        in_flight.push(
+                            async move {
+                                let result = rule.apply(ctx).await;
+                                ConcurrentStep::Applied { weight, group, result }
+                            }
+                            .boxed(),
+                        );
+                    }
+                    Ok(false) => {
+                        running_global -= weight;
+                        if let Some(g) = &group {
+                            if let Some(count) = running_group.get_mut(g) {
+                                *count -= weight;
+                            }
+                        }
+                    }
+                    Err(e) => return Err(RuleEngineError::Evaluation(e)),
+                },
+                ConcurrentStep::Applied { weight, group, result } => {
+                    running_global -= weight;
+                    if let Some(g) = &group {
+                        if let Some(count) = running_group.get_mut(g) {
+                            *count -= weight;
+                        }
+                    }
+                    match result {
+                        Ok(out) => results.push(out),
+                        Err(e) => return Err(RuleEngineError::Application(e)),
+                    }
+                }
+            }
+        }
+
+        Ok(results)
+    }
 }
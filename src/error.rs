@@ -26,6 +26,36 @@ pub enum RuleEngineError<E> {
     /// A fallback error variant for unknown or uncategorized failures.
     #[error("Unknown rule error")]
     Unknown,
+
+    /// Indicates that a fixpoint evaluation (e.g. [`crate::engine::MutableRuleEngine::evaluate_saturate`])
+    /// did not reach a stable state within the allotted number of rounds.
+    #[error("rule set did not reach a fixpoint within {rounds} round(s)")]
+    Cycle {
+        /// The number of rounds that were run before giving up.
+        rounds: usize,
+    },
+
+    /// Indicates that a rule's `weight()` exceeds the global or group budget
+    /// passed to [`crate::engine::RuleEngine::evaluate_concurrent`], so it
+    /// could never be scheduled.
+    #[error("rule `{rule_name}` has weight {weight} which exceeds its budget of {limit}")]
+    WeightExceedsBudget {
+        /// The offending rule's name.
+        rule_name: String,
+        /// The rule's declared weight.
+        weight: u32,
+        /// The global or group limit it exceeds.
+        limit: u32,
+    },
+}
+
+/// Errors that can occur while resolving named rule sets registered with a
+/// [`crate::builder::RuleEngineBuilder`].
+#[derive(Debug, Error)]
+pub enum RuleSetError {
+    /// No rule set was registered under the given name.
+    #[error("rule set not found: {0}")]
+    RuleSetNotFound(String),
 }
 
 /// A concrete error type representing possible failures encountered during
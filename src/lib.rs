@@ -128,13 +128,24 @@ pub mod builder;
 /// Utility enums or structs used across the crate, such as [`PriorityOrder`].
 pub mod utils;
 
+/// An `IndexMap`-backed engine supporting runtime priority changes; see
+/// [`priority_queue::PriorityRuleQueue`].
+pub mod priority_queue;
+
 // Public re-exports
 pub use traits::Rule;
 pub use engine::RuleEngine;
 pub use builder::RuleEngineBuilder;
 pub use utils::PriorityOrder;
-
+pub use priority_queue::PriorityRuleQueue;
+pub use engine::BucketedRuleEngine;
+pub use builder::RuleSet;
+pub use error::RuleSetError;
+pub use utils::{RuleOutcome, RuleRecord, RuleReport, TraceEntry};
 
 pub use traits::MutableRule;
 pub use engine::MutableRuleEngine;
 pub use builder::MutableRuleEngineBuilder;
+
+#[cfg(feature = "async")]
+pub use traits::AsyncRule;
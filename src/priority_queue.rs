@@ -0,0 +1,266 @@
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use indexmap::IndexMap;
+
+use crate::error::RuleEngineError;
+use crate::traits::Rule;
+use crate::utils::PriorityOrder;
+
+/// A rule engine backed by an [`IndexMap`] and a binary heap of indices,
+/// letting rule priorities be changed at runtime in `O(log N)` instead of
+/// re-sorting the whole rule set.
+///
+/// Modeled on the `priority-queue` crate: rules live in an `IndexMap` keyed
+/// by a stable `Id`, while a parallel heap of entry indices tracks priority
+/// order. Priority is tracked independently of [`Rule::priority`] (which is
+/// only used as the *initial* priority on [`PriorityRuleQueue::insert`]), so
+/// [`PriorityRuleQueue::change_priority`] can adjust precedence — e.g.
+/// demoting a rule that keeps failing — without rebuilding the engine.
+///
+/// Build one from a [`crate::builder::RuleEngineBuilder`] via
+/// [`crate::builder::RuleEngineBuilder::build_indexed`], or construct it
+/// directly with [`PriorityRuleQueue::new`]. [`PriorityRuleQueue::iter`]
+/// visits every rule in priority order without removing it, and
+/// [`PriorityRuleQueue::evaluate_all`]/[`PriorityRuleQueue::evaluate_first`]
+/// provide the same evaluation API as [`crate::engine::RuleEngine`], so
+/// priorities can be adjusted between passes without hand-rolling the
+/// engine loop or destructively popping and re-inserting every rule.
+///
+/// # Type Parameters
+///
+/// * `Id` - A stable key identifying each rule.
+/// * `C` - The context type the rules evaluate against.
+/// * `R` - A type that implements the [`Rule`] trait for context `C`.
+pub struct PriorityRuleQueue<Id, C, R>
+where
+    Id: Eq + Hash,
+{
+    /// The rules, keyed by `Id`, in insertion order.
+    entries: IndexMap<Id, R>,
+
+    /// `priorities[entry_index]` is the current priority of that entry.
+    priorities: Vec<u32>,
+
+    /// A binary heap of entry indices, ordered per `order`.
+    heap: Vec<usize>,
+
+    /// `positions[entry_index]` is that entry's current index within `heap`.
+    positions: Vec<usize>,
+
+    /// Whether the heap root is the lowest or highest priority entry.
+    order: PriorityOrder,
+
+    _marker: PhantomData<C>,
+}
+
+impl<Id, C, R> PriorityRuleQueue<Id, C, R>
+where
+    Id: Eq + Hash,
+    R: Rule<C>,
+{
+    /// Creates a new, empty priority queue with the given priority order.
+    pub fn new(order: PriorityOrder) -> Self {
+        Self {
+            entries: IndexMap::new(),
+            priorities: Vec::new(),
+            heap: Vec::new(),
+            positions: Vec::new(),
+            order,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of rules currently in the queue.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the queue holds no rules.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a rule under `id`, using [`Rule::priority`] as its initial
+    /// priority. If `id` was already present, the old `(priority, rule)` is
+    /// dropped in favor of the new one and the heap is re-settled.
+    pub fn insert(&mut self, id: Id, rule: R) {
+        if let Some(idx) = self.entries.get_index_of(&id) {
+            self.priorities[idx] = rule.priority();
+            self.entries.insert(id, rule);
+            self.resettle(idx);
+            return;
+        }
+
+        let priority = rule.priority();
+        let idx = self.entries.len();
+        self.entries.insert(id, rule);
+        self.priorities.push(priority);
+        self.positions.push(self.heap.len());
+        self.heap.push(idx);
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    /// Changes the priority of the rule registered under `id`, resettling
+    /// the heap in `O(log N)`. Returns `false` if `id` isn't present.
+    pub fn change_priority(&mut self, id: &Id, new_priority: u32) -> bool {
+        let Some(idx) = self.entries.get_index_of(id) else {
+            return false;
+        };
+        self.priorities[idx] = new_priority;
+        self.resettle(idx);
+        true
+    }
+
+    /// Returns the rule currently at the front of priority order, without
+    /// removing it.
+    pub fn peek(&self) -> Option<(&Id, &R)> {
+        let idx = *self.heap.first()?;
+        self.entries.get_index(idx)
+    }
+
+    /// Removes and returns the rule currently at the front of priority
+    /// order.
+    pub fn pop(&mut self) -> Option<(Id, R)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let top_idx = self.heap[0];
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+        self.heap.pop();
+        if !self.heap.is_empty() {
+            self.positions[self.heap[0]] = 0;
+            self.sift_down(0);
+        }
+
+        let moved_idx = self.entries.len() - 1;
+        let (id, rule) = self.entries.swap_remove_index(top_idx).expect("valid index");
+        self.priorities.swap_remove(top_idx);
+
+        if top_idx != moved_idx {
+            let heap_pos_of_moved = self.positions[moved_idx];
+            self.heap[heap_pos_of_moved] = top_idx;
+            self.positions[top_idx] = heap_pos_of_moved;
+        }
+        self.positions.pop();
+
+        Some((id, rule))
+    }
+
+    /// Visits every rule in priority order, without removing any of them.
+    ///
+    /// Unlike [`PriorityRuleQueue::pop`], this is non-destructive, so a
+    /// caller can run a pass over every rule and then call
+    /// [`PriorityRuleQueue::change_priority`] for the next pass without
+    /// re-inserting anything.
+    pub fn iter(&self) -> impl Iterator<Item = (&Id, &R)> + '_ {
+        let mut order: Vec<usize> = (0..self.entries.len()).collect();
+        match self.order {
+            PriorityOrder::Asc => order.sort_by_key(|&idx| self.priorities[idx]),
+            PriorityOrder::Desc => order.sort_by_key(|&idx| std::cmp::Reverse(self.priorities[idx])),
+        }
+        order
+            .into_iter()
+            .map(move |idx| self.entries.get_index(idx).expect("valid index"))
+    }
+
+    /// Evaluates all rules and applies those that return `true` from
+    /// [`Rule::evaluate`], visiting them in priority order via
+    /// [`PriorityRuleQueue::iter`]. See [`crate::engine::RuleEngine::evaluate_all`].
+    pub fn evaluate_all(&self, ctx: &C) -> Result<Vec<R::Output>, RuleEngineError<R::RuleError>> {
+        let mut results = Vec::new();
+
+        for (_, rule) in self.iter() {
+            if rule.evaluate(ctx).map_err(RuleEngineError::Evaluation)? {
+                let out = rule.apply(ctx).map_err(RuleEngineError::Application)?;
+                results.push(out);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Evaluates rules in priority order and returns the output of the
+    /// first rule that applies. See [`crate::engine::RuleEngine::evaluate_first`].
+    pub fn evaluate_first(&self, ctx: &C) -> Result<Option<R::Output>, RuleEngineError<R::RuleError>> {
+        for (_, rule) in self.iter() {
+            if rule.evaluate(ctx).map_err(RuleEngineError::Evaluation)? {
+                return rule
+                    .apply(ctx)
+                    .map(Some)
+                    .map_err(RuleEngineError::Application);
+            }
+        }
+        Ok(None)
+    }
+
+    /// `true` if `a`'s priority should sit closer to the heap root than `b`'s.
+    fn is_higher(&self, a: u32, b: u32) -> bool {
+        match self.order {
+            PriorityOrder::Asc => a < b,
+            PriorityOrder::Desc => a > b,
+        }
+    }
+
+    /// Resettles the heap after `entry_idx`'s priority changed, sifting it
+    /// up or down from its current position as needed.
+    fn resettle(&mut self, entry_idx: usize) {
+        let heap_pos = self.positions[entry_idx];
+        if !self.sift_up(heap_pos) {
+            self.sift_down(heap_pos);
+        }
+    }
+
+    /// Sifts the entry at `heap_pos` up while it's higher priority than its
+    /// parent. Returns whether any move happened.
+    fn sift_up(&mut self, mut heap_pos: usize) -> bool {
+        let mut moved = false;
+        while heap_pos > 0 {
+            let parent = (heap_pos - 1) / 2;
+            if self.is_higher(
+                self.priorities[self.heap[heap_pos]],
+                self.priorities[self.heap[parent]],
+            ) {
+                self.heap.swap(heap_pos, parent);
+                self.positions[self.heap[heap_pos]] = heap_pos;
+                self.positions[self.heap[parent]] = parent;
+                heap_pos = parent;
+                moved = true;
+            } else {
+                break;
+            }
+        }
+        moved
+    }
+
+    /// Sifts the entry at `heap_pos` down while a child is higher priority.
+    fn sift_down(&mut self, mut heap_pos: usize) {
+        loop {
+            let left = 2 * heap_pos + 1;
+            let right = 2 * heap_pos + 2;
+            let mut best = heap_pos;
+
+            if left < self.heap.len()
+                && self.is_higher(self.priorities[self.heap[left]], self.priorities[self.heap[best]])
+            {
+                best = left;
+            }
+            if right < self.heap.len()
+                && self.is_higher(self.priorities[self.heap[right]], self.priorities[self.heap[best]])
+            {
+                best = right;
+            }
+
+            if best == heap_pos {
+                break;
+            }
+
+            self.heap.swap(heap_pos, best);
+            self.positions[self.heap[heap_pos]] = heap_pos;
+            self.positions[self.heap[best]] = best;
+            heap_pos = best;
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::utils::RuleOutcome;
+
 /// A generic trait that defines a rule which can be evaluated and applied
 /// based on a given context `C`.
 ///
@@ -46,6 +48,16 @@ pub trait Rule<C> {
     /// * `Err(RuleError)` if an error occurs during application.
     fn apply(&self, ctx: &C) -> Result<Self::Output, Self::RuleError>;
 
+    /// Returns the name of this rule, for diagnostics and tracing.
+    ///
+    /// The default falls back to the rule's type name, which is enough to
+    /// tell rules apart in a trace without requiring every `Rule` impl to
+    /// override it. Override this for enum-style rules where the type name
+    /// alone would be the same for every variant.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
     /// Returns the priority of this rule.
     ///
     /// This value can be used to determine the order in which multiple rules are
@@ -58,6 +70,23 @@ pub trait Rule<C> {
     fn priority(&self) -> u32 {
         0
     }
+
+    /// Classifies this rule's outcome for the given context as one of the
+    /// three [`RuleOutcome`] states, for use in an aggregated
+    /// [`crate::utils::RuleReport`].
+    ///
+    /// The default implementation simply maps [`Rule::evaluate`] to
+    /// `Applied`/`Skipped`. Override it for rules that can legitimately
+    /// fail to determine whether they apply (e.g. missing data), returning
+    /// `RuleOutcome::Undetermined` in that case instead of an error or a
+    /// guessed `false`.
+    fn classify(&self, ctx: &C) -> Result<RuleOutcome, Self::RuleError> {
+        Ok(if self.evaluate(ctx)? {
+            RuleOutcome::Applied
+        } else {
+            RuleOutcome::Skipped
+        })
+    }
 }
 /// A generic trait representing a **mutable** rule that may alter the context `C`
 /// during application.
@@ -123,3 +152,51 @@ pub trait MutableRule<C> {
     /// Use this to clean up, log results, or trigger downstream effects.
     fn after_apply(&self, _ctx: &C) {}
 }
+
+/// A rule whose `evaluate`/`apply` perform asynchronous I/O (network
+/// checks, database lookups, etc.), for use with
+/// [`crate::engine::RuleEngine::evaluate_concurrent`].
+///
+/// Only available with the `async` feature enabled.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncRule<C: Sync>: Send + Sync {
+    /// The output type produced by this rule when successfully applied.
+    type Output: Send;
+
+    /// The error type that may be returned during evaluation or application.
+    type RuleError: Send;
+
+    /// Evaluates whether this rule is applicable to the given context.
+    async fn evaluate(&self, ctx: &C) -> Result<bool, Self::RuleError>;
+
+    /// Applies the rule to the given context and produces an output.
+    async fn apply(&self, ctx: &C) -> Result<Self::Output, Self::RuleError>;
+
+    /// Returns the name of this rule, for diagnostics (e.g. which rule
+    /// exceeded a weight budget). Defaults to the rule's type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns the priority rules are started in: ascending or descending
+    /// by this value, per the engine's configured
+    /// [`crate::utils::PriorityOrder`]. By default, the priority is `0`.
+    fn priority(&self) -> u32 {
+        0
+    }
+
+    /// The relative concurrency cost of running this rule. Defaults to `1`.
+    /// Used against the `global_limit` and, if [`AsyncRule::group`] returns
+    /// `Some`, against that group's limit.
+    fn weight(&self) -> u32 {
+        1
+    }
+
+    /// An optional concurrency group. Rules sharing a group are bound by
+    /// that group's weight cap (if one is configured) in addition to the
+    /// global budget. Rules with no group are only bound by the global budget.
+    fn group(&self) -> Option<&str> {
+        None
+    }
+}
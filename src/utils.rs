@@ -13,3 +13,101 @@ pub enum PriorityOrder {
     /// Descending order: higher priority values come first.
     Desc,
 }
+
+/// The three-valued outcome of evaluating a single rule, as recorded in a
+/// [`RuleReport`].
+///
+/// This distinguishes a rule that was checked and did not apply from one
+/// that could not determine whether it applies at all (e.g. missing data),
+/// which a plain `bool` collapses into the same `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleOutcome {
+    /// The rule evaluated to `true` and was applied.
+    Applied,
+
+    /// The rule evaluated to `false` and was skipped.
+    Skipped,
+
+    /// The rule could not determine whether it applies.
+    Undetermined,
+}
+
+/// A single rule's record within a [`RuleReport`].
+#[derive(Debug, Clone)]
+pub struct RuleRecord<O> {
+    /// The rule's name, as reported by the rule.
+    pub name: String,
+
+    /// The rule's outcome, or `None` if evaluating or applying it errored.
+    pub outcome: Option<RuleOutcome>,
+
+    /// The rule's output, present only when `outcome` is `Some(RuleOutcome::Applied)`.
+    pub output: Option<O>,
+}
+
+/// An aggregated, lint-style report produced by running every rule in a
+/// `RuleEngine` against a context, as returned by
+/// [`crate::engine::RuleEngine::evaluate_report`].
+#[derive(Debug, Clone)]
+pub struct RuleReport<O> {
+    /// The per-rule records, in evaluation order.
+    pub records: Vec<RuleRecord<O>>,
+
+    /// How many rules applied.
+    pub applied: usize,
+
+    /// How many rules were skipped.
+    pub skipped: usize,
+
+    /// How many rules could not determine whether they applied.
+    pub undetermined: usize,
+
+    /// How many rules errored during evaluation or application.
+    pub errored: usize,
+}
+
+/// The result of calling [`crate::traits::Rule::evaluate`] for a single rule
+/// within a [`TraceEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceEvaluation {
+    /// `evaluate` returned `Ok(true)`.
+    True,
+
+    /// `evaluate` returned `Ok(false)`.
+    False,
+
+    /// `evaluate` returned `Err`.
+    Errored,
+}
+
+/// A single step recorded by [`crate::engine::RuleEngine::evaluate_all_traced`] /
+/// [`crate::engine::RuleEngine::evaluate_first_traced`].
+///
+/// Enough is captured per rule to reconstruct the decision path a trace was
+/// run for, including which rule (if any) `evaluate_first_traced` stopped at.
+#[derive(Debug, Clone)]
+pub struct TraceEntry {
+    /// The rule's name, as reported by [`crate::traits::Rule::name`].
+    pub rule_name: String,
+
+    /// The priority the rule ran at.
+    pub priority: u32,
+
+    /// What `evaluate` returned for this rule.
+    pub evaluation: TraceEvaluation,
+
+    /// Whether `apply` ran, and if so whether it succeeded. `None` means
+    /// `apply` was never called (the rule didn't evaluate to `true`).
+    pub applied: Option<bool>,
+}
+
+impl<O> RuleReport<O> {
+    /// Returns a short human-readable tally, e.g.
+    /// `"3 applied, 1 skipped, 0 undetermined, 0 errored"`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} applied, {} skipped, {} undetermined, {} errored",
+            self.applied, self.skipped, self.undetermined, self.errored
+        )
+    }
+}
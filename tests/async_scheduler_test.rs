@@ -0,0 +1,134 @@
+#![cfg(feature = "async")]
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use rule_kit::error::RuleEngineError;
+use rule_kit::traits::AsyncRule;
+use rule_kit::utils::PriorityOrder;
+use rule_kit::RuleEngine;
+
+struct CountingRule {
+    name: &'static str,
+    weight: u32,
+    group: Option<&'static str>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl AsyncRule<()> for CountingRule {
+    type Output = &'static str;
+    type RuleError = ();
+
+    async fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+        tokio::task::yield_now().await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(true)
+    }
+
+    async fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        Ok(self.name)
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    fn group(&self) -> Option<&str> {
+        self.group
+    }
+}
+
+fn engine_of(rules: Vec<CountingRule>) -> RuleEngine<(), CountingRule> {
+    RuleEngine {
+        _rules: rules,
+        _order: PriorityOrder::Asc,
+        _marker: PhantomData,
+    }
+}
+
+#[tokio::test]
+async fn rejects_a_rule_whose_weight_exceeds_the_global_budget() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let engine = engine_of(vec![CountingRule {
+        name: "too-heavy",
+        weight: 5,
+        group: None,
+        in_flight,
+        max_in_flight,
+    }]);
+
+    let result = engine.evaluate_concurrent(&(), 3, &HashMap::new()).await;
+
+    match result {
+        Err(RuleEngineError::WeightExceedsBudget {
+            rule_name, weight, limit,
+        }) => {
+            assert_eq!(rule_name, "too-heavy");
+            assert_eq!(weight, 5);
+            assert_eq!(limit, 3);
+        }
+        other => panic!("expected WeightExceedsBudget, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn runs_independent_rules_concurrently_up_to_the_global_budget() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let rules = (0..4)
+        .map(|i| CountingRule {
+            name: Box::leak(format!("rule-{i}").into_boxed_str()),
+            weight: 1,
+            group: None,
+            in_flight: in_flight.clone(),
+            max_in_flight: max_in_flight.clone(),
+        })
+        .collect();
+    let engine = engine_of(rules);
+
+    let results = engine
+        .evaluate_concurrent(&(), 4, &HashMap::new())
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 4);
+    assert!(max_in_flight.load(Ordering::SeqCst) > 1);
+}
+
+#[tokio::test]
+async fn group_limit_caps_concurrency_within_a_group() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let rules = (0..4)
+        .map(|i| CountingRule {
+            name: Box::leak(format!("rule-{i}").into_boxed_str()),
+            weight: 1,
+            group: Some("slow-db"),
+            in_flight: in_flight.clone(),
+            max_in_flight: max_in_flight.clone(),
+        })
+        .collect();
+    let engine = engine_of(rules);
+
+    let mut group_limits = HashMap::new();
+    group_limits.insert("slow-db".to_string(), 1);
+
+    let results = engine
+        .evaluate_concurrent(&(), 4, &group_limits)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+}
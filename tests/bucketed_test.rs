@@ -0,0 +1,87 @@
+use rule_kit::builder::RuleEngineBuilder;
+use rule_kit::utils::PriorityOrder;
+use rule_kit::Rule;
+
+#[derive(Debug, Clone)]
+struct NamedRule {
+    id: &'static str,
+    priority: u32,
+}
+
+impl Rule<()> for NamedRule {
+    type Output = &'static str;
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.id
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        Ok(true)
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        Ok(self.id)
+    }
+
+    fn priority(&self) -> u32 {
+        self.priority
+    }
+}
+
+#[test]
+fn evaluates_in_ascending_bucket_order_by_default() {
+    let rules = vec![
+        NamedRule { id: "high", priority: 2 },
+        NamedRule { id: "low", priority: 0 },
+        NamedRule { id: "mid", priority: 1 },
+    ];
+
+    let engine = RuleEngineBuilder::new().with_rules(rules).build_bucketed(2);
+
+    let results = engine.evaluate_all(&()).unwrap();
+    assert_eq!(results, vec!["low", "mid", "high"]);
+}
+
+#[test]
+fn evaluates_in_descending_bucket_order_when_configured() {
+    let rules = vec![
+        NamedRule { id: "high", priority: 2 },
+        NamedRule { id: "low", priority: 0 },
+        NamedRule { id: "mid", priority: 1 },
+    ];
+
+    let engine = RuleEngineBuilder::new()
+        .with_rules(rules)
+        .priority(PriorityOrder::Desc)
+        .build_bucketed(2);
+
+    let results = engine.evaluate_all(&()).unwrap();
+    assert_eq!(results, vec!["high", "mid", "low"]);
+}
+
+#[test]
+fn priorities_above_max_are_clamped_into_the_last_bucket() {
+    let rules = vec![
+        NamedRule { id: "over", priority: 100 },
+        NamedRule { id: "at-max", priority: 2 },
+    ];
+
+    let engine = RuleEngineBuilder::new().with_rules(rules).build_bucketed(2);
+
+    let results = engine.evaluate_all(&()).unwrap();
+    assert_eq!(results, vec!["over", "at-max"]);
+}
+
+#[test]
+fn evaluate_first_returns_only_the_first_bucket_order_match() {
+    let rules = vec![
+        NamedRule { id: "low", priority: 0 },
+        NamedRule { id: "mid", priority: 1 },
+    ];
+
+    let engine = RuleEngineBuilder::new().with_rules(rules).build_bucketed(1);
+
+    let result = engine.evaluate_first(&()).unwrap();
+    assert_eq!(result, Some("low"));
+}
@@ -0,0 +1,65 @@
+use rule_kit::builder::RuleEngineBuilder;
+use rule_kit::Rule;
+
+#[derive(Debug, Clone)]
+struct NamedRule {
+    id: &'static str,
+    priority: u32,
+}
+
+impl Rule<()> for NamedRule {
+    type Output = &'static str;
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.id
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        Ok(true)
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        Ok(self.id)
+    }
+
+    fn priority(&self) -> u32 {
+        self.priority
+    }
+}
+
+#[test]
+fn custom_comparator_overrides_the_asc_desc_preset() {
+    let rules = vec![
+        NamedRule { id: "a", priority: 1 },
+        NamedRule { id: "b", priority: 2 },
+        NamedRule { id: "c", priority: 3 },
+    ];
+
+    // Set ascending, but override with a comparator that sorts by name descending.
+    let engine = RuleEngineBuilder::new()
+        .with_rules(rules)
+        .priority_asc()
+        .order_by(|a, b| b.name().cmp(a.name()))
+        .build();
+
+    let order: Vec<&str> = engine._rules.iter().map(|r| r.id).collect();
+    assert_eq!(order, vec!["c", "b", "a"]);
+}
+
+#[test]
+fn custom_comparator_can_combine_priority_and_name() {
+    let rules = vec![
+        NamedRule { id: "z", priority: 1 },
+        NamedRule { id: "y", priority: 1 },
+        NamedRule { id: "x", priority: 0 },
+    ];
+
+    let engine = RuleEngineBuilder::new()
+        .with_rules(rules)
+        .order_by(|a, b| a.priority.cmp(&b.priority).then_with(|| a.id.cmp(b.id)))
+        .build();
+
+    let order: Vec<&str> = engine._rules.iter().map(|r| r.id).collect();
+    assert_eq!(order, vec!["x", "y", "z"]);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "rayon")]
+
+use rule_kit::{Rule, RuleEngine};
+
+#[derive(Clone)]
+enum ParRule {
+    Applies(&'static str, u32),
+    Skips(&'static str, u32),
+    Errors(&'static str, u32),
+}
+
+impl Rule<()> for ParRule {
+    type Output = &'static str;
+    type RuleError = &'static str;
+
+    fn name(&self) -> &str {
+        match self {
+            ParRule::Applies(name, _) => name,
+            ParRule::Skips(name, _) => name,
+            ParRule::Errors(name, _) => name,
+        }
+    }
+
+    fn priority(&self) -> u32 {
+        match self {
+            ParRule::Applies(_, p) => *p,
+            ParRule::Skips(_, p) => *p,
+            ParRule::Errors(_, p) => *p,
+        }
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        match self {
+            ParRule::Applies(..) => Ok(true),
+            ParRule::Skips(..) => Ok(false),
+            ParRule::Errors(name, _) => Err(*name),
+        }
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        match self {
+            ParRule::Applies(name, _) => Ok(name),
+            _ => unreachable!("apply only called after evaluate returns Ok(true)"),
+        }
+    }
+}
+
+#[test]
+fn preserves_registration_order_within_and_across_priority_groups() {
+    let rules = vec![
+        ParRule::Applies("b", 1),
+        ParRule::Applies("a", 1),
+        ParRule::Skips("skip-me", 1),
+        ParRule::Applies("z", 2),
+    ];
+    let engine = RuleEngine::new(rules, None);
+
+    let results = engine.evaluate_all_par(&()).unwrap();
+
+    assert_eq!(results, vec!["b", "a", "z"]);
+}
+
+#[test]
+fn returns_the_lowest_index_error_deterministically() {
+    let rules = vec![
+        ParRule::Applies("first", 1),
+        ParRule::Errors("second", 1),
+        ParRule::Errors("third", 1),
+    ];
+    let engine = RuleEngine::new(rules, None);
+
+    let result = engine.evaluate_all_par(&());
+
+    match result {
+        Err(rule_kit::error::RuleEngineError::Evaluation(e)) => assert_eq!(e, "second"),
+        other => panic!("expected Evaluation error, got {other:?}"),
+    }
+}
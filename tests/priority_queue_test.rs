@@ -0,0 +1,117 @@
+use rule_kit::utils::PriorityOrder;
+use rule_kit::{PriorityRuleQueue, Rule};
+
+#[derive(Debug, Clone, PartialEq)]
+struct NamedRule {
+    id: &'static str,
+    priority: u32,
+}
+
+impl Rule<()> for NamedRule {
+    type Output = &'static str;
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.id
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        Ok(true)
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        Ok(self.id)
+    }
+
+    fn priority(&self) -> u32 {
+        self.priority
+    }
+}
+
+fn queue_with(rules: Vec<(&'static str, u32)>, order: PriorityOrder) -> PriorityRuleQueue<&'static str, (), NamedRule> {
+    let mut queue = PriorityRuleQueue::new(order);
+    for (id, priority) in rules {
+        queue.insert(id, NamedRule { id, priority });
+    }
+    queue
+}
+
+#[test]
+fn pop_returns_rules_in_descending_priority_order() {
+    let mut queue = queue_with(
+        vec![("low", 1), ("high", 10), ("mid", 5)],
+        PriorityOrder::Desc,
+    );
+
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.pop().map(|(id, _)| id), Some("high"));
+    assert_eq!(queue.pop().map(|(id, _)| id), Some("mid"));
+    assert_eq!(queue.pop().map(|(id, _)| id), Some("low"));
+    assert_eq!(queue.pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn change_priority_resettles_the_heap() {
+    let mut queue = queue_with(
+        vec![("a", 1), ("b", 2), ("c", 3)],
+        PriorityOrder::Desc,
+    );
+
+    assert_eq!(queue.peek().map(|(id, _)| *id), Some("c"));
+
+    assert!(queue.change_priority(&"a", 100));
+    assert_eq!(queue.peek().map(|(id, _)| *id), Some("a"));
+
+    assert!(!queue.change_priority(&"missing", 1));
+}
+
+#[test]
+fn iter_is_non_destructive_and_priority_ordered() {
+    let queue = queue_with(
+        vec![("low", 1), ("high", 10), ("mid", 5)],
+        PriorityOrder::Desc,
+    );
+
+    let seen: Vec<&str> = queue.iter().map(|(id, _)| *id).collect();
+    assert_eq!(seen, vec!["high", "mid", "low"]);
+
+    // Non-destructive: the queue still has every rule afterward.
+    assert_eq!(queue.len(), 3);
+    let seen_again: Vec<&str> = queue.iter().map(|(id, _)| *id).collect();
+    assert_eq!(seen_again, vec!["high", "mid", "low"]);
+}
+
+#[test]
+fn evaluate_all_applies_every_rule_in_priority_order() {
+    let queue = queue_with(
+        vec![("low", 1), ("high", 10), ("mid", 5)],
+        PriorityOrder::Desc,
+    );
+
+    let results = queue.evaluate_all(&()).unwrap();
+    assert_eq!(results, vec!["high", "mid", "low"]);
+}
+
+#[test]
+fn evaluate_first_returns_only_the_top_priority_rule() {
+    let queue = queue_with(
+        vec![("low", 1), ("high", 10), ("mid", 5)],
+        PriorityOrder::Asc,
+    );
+
+    let result = queue.evaluate_first(&()).unwrap();
+    assert_eq!(result, Some("low"));
+}
+
+#[test]
+fn pop_bookkeeping_survives_repeated_inserts_and_pops() {
+    let mut queue = queue_with(vec![("a", 3), ("b", 1), ("c", 2)], PriorityOrder::Desc);
+
+    assert_eq!(queue.pop().map(|(id, _)| id), Some("a"));
+    queue.insert("d", NamedRule { id: "d", priority: 5 });
+    queue.insert("e", NamedRule { id: "e", priority: 0 });
+
+    let order: Vec<&str> = queue.iter().map(|(id, _)| *id).collect();
+    assert_eq!(order, vec!["d", "c", "b", "e"]);
+}
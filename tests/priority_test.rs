@@ -1,4 +1,4 @@
-use rule_kit::Rule;
+use rule_kit::MutableRule;
 
 #[derive(Debug)]
 pub struct UserContext {
@@ -13,7 +13,7 @@ pub struct AgeRule;
 #[derive(Debug)]
 pub struct ScoreRule;
 
-impl Rule<UserContext> for AgeRule {
+impl MutableRule<UserContext> for AgeRule {
     type RuleError = ();
 
     fn name(&self) -> &str {
@@ -42,7 +42,7 @@ impl Rule<UserContext> for AgeRule {
     }
 }
 
-impl Rule<UserContext> for ScoreRule {
+impl MutableRule<UserContext> for ScoreRule {
     type RuleError = ();
 
     fn name(&self) -> &str {
@@ -77,7 +77,7 @@ pub enum UserRule {
     Score(ScoreRule),
 }
 
-impl Rule<UserContext> for UserRule {
+impl MutableRule<UserContext> for UserRule {
     type RuleError = ();
 
     fn name(&self) -> &str {
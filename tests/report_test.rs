@@ -0,0 +1,93 @@
+use rule_kit::utils::RuleOutcome;
+use rule_kit::{Rule, RuleEngine};
+
+enum ReportRule {
+    Applies,
+    Skips,
+    /// Always classifies as `Undetermined`, overriding the default
+    /// evaluate-based classification.
+    CannotTell,
+    /// Returns `Ok(true)` from `evaluate` but errors during `apply`.
+    ErrorsOnApply,
+}
+
+impl Rule<()> for ReportRule {
+    type Output = &'static str;
+    type RuleError = &'static str;
+
+    fn name(&self) -> &str {
+        match self {
+            ReportRule::Applies => "applies",
+            ReportRule::Skips => "skips",
+            ReportRule::CannotTell => "cannot-tell",
+            ReportRule::ErrorsOnApply => "errors-on-apply",
+        }
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        match self {
+            ReportRule::Applies => Ok(true),
+            ReportRule::Skips => Ok(false),
+            ReportRule::CannotTell => Ok(false),
+            ReportRule::ErrorsOnApply => Ok(true),
+        }
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        match self {
+            ReportRule::Applies => Ok("applied-output"),
+            ReportRule::ErrorsOnApply => Err("boom"),
+            _ => unreachable!("apply only called when evaluate/classify says so"),
+        }
+    }
+
+    fn classify(&self, ctx: &()) -> Result<RuleOutcome, Self::RuleError> {
+        match self {
+            ReportRule::CannotTell => Ok(RuleOutcome::Undetermined),
+            _ => {
+                if self.evaluate(ctx)? {
+                    Ok(RuleOutcome::Applied)
+                } else {
+                    Ok(RuleOutcome::Skipped)
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn classifies_and_tallies_every_outcome() {
+    let rules = vec![
+        ReportRule::Applies,
+        ReportRule::Skips,
+        ReportRule::CannotTell,
+        ReportRule::ErrorsOnApply,
+    ];
+    let engine = RuleEngine::new(rules, None);
+
+    let report = engine.evaluate_report(&());
+
+    assert_eq!(report.applied, 1);
+    assert_eq!(report.skipped, 1);
+    assert_eq!(report.undetermined, 1);
+    assert_eq!(report.errored, 1);
+    assert_eq!(report.records.len(), 4);
+
+    let applied_record = report
+        .records
+        .iter()
+        .find(|r| r.name == "applies")
+        .unwrap();
+    assert_eq!(applied_record.outcome, Some(RuleOutcome::Applied));
+    assert_eq!(applied_record.output, Some("applied-output"));
+
+    let errored_record = report
+        .records
+        .iter()
+        .find(|r| r.name == "errors-on-apply")
+        .unwrap();
+    assert_eq!(errored_record.outcome, None);
+    assert_eq!(errored_record.output, None);
+
+    assert_eq!(report.summary(), "1 applied, 1 skipped, 1 undetermined, 1 errored");
+}
@@ -0,0 +1,87 @@
+use rule_kit::builder::{RuleEngineBuilder, RuleSet};
+use rule_kit::error::RuleSetError;
+use rule_kit::Rule;
+
+#[derive(Debug, Clone, PartialEq)]
+struct NamedRule {
+    id: &'static str,
+}
+
+impl Rule<()> for NamedRule {
+    type Output = &'static str;
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.id
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        Ok(true)
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        Ok(self.id)
+    }
+}
+
+#[test]
+fn activating_a_set_pulls_in_its_transitive_dependencies_in_priority_order() {
+    let base = RuleSet::new("base", 0, vec![NamedRule { id: "base-rule" }]);
+    let middle = RuleSet::new("middle", 1, vec![NamedRule { id: "middle-rule" }])
+        .depends_on(["base"]);
+    let top = RuleSet::new("top", 2, vec![NamedRule { id: "top-rule" }]).depends_on(["middle"]);
+
+    let builder = RuleEngineBuilder::new()
+        .register_rule_set(base)
+        .register_rule_set(middle)
+        .register_rule_set(top)
+        .with_rule_set("top")
+        .unwrap();
+
+    let names: Vec<&str> = builder.rules.iter().map(|r| r.id).collect();
+    assert_eq!(names, vec!["base-rule", "middle-rule", "top-rule"]);
+}
+
+#[test]
+fn activating_overlapping_sets_does_not_duplicate_shared_rules() {
+    let shared = RuleSet::new("shared", 0, vec![NamedRule { id: "shared-rule" }]);
+    let a = RuleSet::new("a", 1, vec![NamedRule { id: "a-rule" }]).depends_on(["shared"]);
+    let b = RuleSet::new("b", 1, vec![NamedRule { id: "b-rule" }]).depends_on(["shared"]);
+
+    let builder = RuleEngineBuilder::new()
+        .register_rule_set(shared)
+        .register_rule_set(a)
+        .register_rule_set(b)
+        .with_rule_sets(&["a", "b"])
+        .unwrap();
+
+    let shared_count = builder.rules.iter().filter(|r| r.id == "shared-rule").count();
+    assert_eq!(shared_count, 1);
+    assert_eq!(builder.rules.len(), 3);
+}
+
+#[test]
+fn activating_an_unregistered_set_errors_with_rule_set_not_found() {
+    let builder: RuleEngineBuilder<(), NamedRule> = RuleEngineBuilder::new();
+
+    let result = builder.with_rule_set("missing");
+
+    match result {
+        Err(RuleSetError::RuleSetNotFound(name)) => assert_eq!(name, "missing"),
+        other => panic!("expected RuleSetNotFound, got {:?}", other.map(|b| b.rules.len())),
+    }
+}
+
+#[test]
+fn an_unregistered_transitive_dependency_also_errors() {
+    let a = RuleSet::new("a", 0, vec![NamedRule { id: "a-rule" }]).depends_on(["missing-dep"]);
+
+    let builder = RuleEngineBuilder::new().register_rule_set(a);
+
+    let result = builder.with_rule_set("a");
+
+    match result {
+        Err(RuleSetError::RuleSetNotFound(name)) => assert_eq!(name, "missing-dep"),
+        other => panic!("expected RuleSetNotFound, got {:?}", other.map(|b| b.rules.len())),
+    }
+}
@@ -0,0 +1,173 @@
+use rule_kit::error::RuleEngineError;
+use rule_kit::{MutableRule, MutableRuleEngine};
+
+struct Counter {
+    value: u32,
+}
+
+/// Fires once `ctx.value` reaches `threshold`, bumping it by `bump` so a
+/// later rule's threshold can become satisfied in a subsequent round.
+struct BumpWhenAtLeast {
+    name: &'static str,
+    threshold: u32,
+    bump: u32,
+    fired: bool,
+}
+
+impl MutableRule<Counter> for BumpWhenAtLeast {
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn evaluate(&self, ctx: &Counter) -> Result<bool, Self::RuleError> {
+        Ok(!self.fired && ctx.value >= self.threshold)
+    }
+
+    fn apply(&mut self, ctx: &mut Counter) -> Result<(), Self::RuleError> {
+        ctx.value += self.bump;
+        self.fired = true;
+        Ok(())
+    }
+}
+
+struct NeverApplicable {
+    name: &'static str,
+}
+
+impl MutableRule<Counter> for NeverApplicable {
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn evaluate(&self, _ctx: &Counter) -> Result<bool, Self::RuleError> {
+        Ok(false)
+    }
+
+    fn apply(&mut self, _ctx: &mut Counter) -> Result<(), Self::RuleError> {
+        unreachable!("evaluate always returns false")
+    }
+}
+
+/// Wraps the two rule types above so they can share a single
+/// `MutableRuleEngine<Counter, SaturateRule>`.
+enum SaturateRule {
+    Bump(BumpWhenAtLeast),
+    Never(NeverApplicable),
+}
+
+impl MutableRule<Counter> for SaturateRule {
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        match self {
+            SaturateRule::Bump(r) => r.name(),
+            SaturateRule::Never(r) => r.name(),
+        }
+    }
+
+    fn evaluate(&self, ctx: &Counter) -> Result<bool, Self::RuleError> {
+        match self {
+            SaturateRule::Bump(r) => r.evaluate(ctx),
+            SaturateRule::Never(r) => r.evaluate(ctx),
+        }
+    }
+
+    fn apply(&mut self, ctx: &mut Counter) -> Result<(), Self::RuleError> {
+        match self {
+            SaturateRule::Bump(r) => r.apply(ctx),
+            SaturateRule::Never(r) => r.apply(ctx),
+        }
+    }
+}
+
+#[test]
+fn chains_rules_across_rounds_until_no_rule_fires() {
+    // stage-2 is registered first, so it is passed over in round 1 and only
+    // fires in round 2, after stage-1 has bumped the value.
+    let rules = vec![
+        BumpWhenAtLeast {
+            name: "stage-2",
+            threshold: 2,
+            bump: 1,
+            fired: false,
+        },
+        BumpWhenAtLeast {
+            name: "stage-1",
+            threshold: 1,
+            bump: 1,
+            fired: false,
+        },
+    ];
+    let mut engine = MutableRuleEngine::new(rules, None);
+    let mut ctx = Counter { value: 1 };
+
+    let unfired = engine.evaluate_saturate(&mut ctx, 10).unwrap();
+
+    assert!(unfired.is_empty());
+    assert_eq!(ctx.value, 3);
+}
+
+#[test]
+fn returns_names_of_rules_that_never_became_applicable() {
+    let rules = vec![
+        SaturateRule::Bump(BumpWhenAtLeast {
+            name: "stage-1",
+            threshold: 1,
+            bump: 1,
+            fired: false,
+        }),
+        SaturateRule::Never(NeverApplicable { name: "dead-rule" }),
+    ];
+    let mut engine = MutableRuleEngine::new(rules, None);
+    let mut ctx = Counter { value: 1 };
+
+    let unfired = engine.evaluate_saturate(&mut ctx, 10).unwrap();
+
+    assert_eq!(unfired, vec!["dead-rule".to_string()]);
+}
+
+#[test]
+fn errors_with_cycle_when_convergence_needs_more_rounds_than_allowed() {
+    // Each rule only unlocks once the previous one has fired, and they're
+    // registered in reverse dependency order, so exactly one new rule can
+    // fire per round: 4 rules need 4 rounds to fully converge.
+    let rules = vec![
+        BumpWhenAtLeast {
+            name: "stage-4",
+            threshold: 3,
+            bump: 1,
+            fired: false,
+        },
+        BumpWhenAtLeast {
+            name: "stage-3",
+            threshold: 2,
+            bump: 1,
+            fired: false,
+        },
+        BumpWhenAtLeast {
+            name: "stage-2",
+            threshold: 1,
+            bump: 1,
+            fired: false,
+        },
+        BumpWhenAtLeast {
+            name: "stage-1",
+            threshold: 0,
+            bump: 1,
+            fired: false,
+        },
+    ];
+    let mut engine = MutableRuleEngine::new(rules, None);
+    let mut ctx = Counter { value: 0 };
+
+    let result = engine.evaluate_saturate(&mut ctx, 2);
+
+    match result {
+        Err(RuleEngineError::Cycle { rounds }) => assert_eq!(rounds, 2),
+        other => panic!("expected Cycle error, got {other:?}"),
+    }
+}
@@ -0,0 +1,78 @@
+use rule_kit::builder::RuleEngineBuilder;
+use rule_kit::Rule;
+
+#[derive(Debug, Clone)]
+struct NamedRule {
+    id: &'static str,
+    priority: u32,
+}
+
+impl Rule<()> for NamedRule {
+    type Output = &'static str;
+    type RuleError = ();
+
+    fn name(&self) -> &str {
+        self.id
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        Ok(true)
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        Ok(self.id)
+    }
+
+    fn priority(&self) -> u32 {
+        self.priority
+    }
+}
+
+#[test]
+fn build_preserves_registration_order_for_equal_priority() {
+    let rules = vec![
+        NamedRule { id: "c", priority: 1 },
+        NamedRule { id: "a", priority: 1 },
+        NamedRule { id: "b", priority: 1 },
+    ];
+
+    let engine = RuleEngineBuilder::new()
+        .with_rules(rules)
+        .priority_asc()
+        .build();
+
+    let order: Vec<&str> = engine._rules.iter().map(|r| r.id).collect();
+    assert_eq!(order, vec!["c", "a", "b"]);
+}
+
+#[test]
+fn order_by_priority_then_name_is_independent_of_registration_order() {
+    let rules_one_order = vec![
+        NamedRule { id: "c", priority: 1 },
+        NamedRule { id: "a", priority: 1 },
+        NamedRule { id: "b", priority: 1 },
+    ];
+    let rules_other_order = vec![
+        NamedRule { id: "b", priority: 1 },
+        NamedRule { id: "c", priority: 1 },
+        NamedRule { id: "a", priority: 1 },
+    ];
+
+    let engine_one = RuleEngineBuilder::new()
+        .with_rules(rules_one_order)
+        .priority_asc()
+        .order_by_priority_then_name()
+        .build();
+
+    let engine_other = RuleEngineBuilder::new()
+        .with_rules(rules_other_order)
+        .priority_asc()
+        .order_by_priority_then_name()
+        .build();
+
+    let order_one: Vec<&str> = engine_one._rules.iter().map(|r| r.id).collect();
+    let order_other: Vec<&str> = engine_other._rules.iter().map(|r| r.id).collect();
+
+    assert_eq!(order_one, vec!["a", "b", "c"]);
+    assert_eq!(order_one, order_other);
+}
@@ -0,0 +1,100 @@
+use rule_kit::utils::TraceEvaluation;
+use rule_kit::{Rule, RuleEngine};
+
+#[derive(Clone)]
+enum TracedRule {
+    Applies(&'static str, u32),
+    Skips(&'static str, u32),
+    Errors(&'static str, u32),
+}
+
+impl Rule<()> for TracedRule {
+    type Output = &'static str;
+    type RuleError = &'static str;
+
+    fn name(&self) -> &str {
+        match self {
+            TracedRule::Applies(name, _) => name,
+            TracedRule::Skips(name, _) => name,
+            TracedRule::Errors(name, _) => name,
+        }
+    }
+
+    fn priority(&self) -> u32 {
+        match self {
+            TracedRule::Applies(_, p) => *p,
+            TracedRule::Skips(_, p) => *p,
+            TracedRule::Errors(_, p) => *p,
+        }
+    }
+
+    fn evaluate(&self, _ctx: &()) -> Result<bool, Self::RuleError> {
+        match self {
+            TracedRule::Applies(..) => Ok(true),
+            TracedRule::Skips(..) => Ok(false),
+            TracedRule::Errors(..) => Err("eval-failed"),
+        }
+    }
+
+    fn apply(&self, _ctx: &()) -> Result<Self::Output, Self::RuleError> {
+        match self {
+            TracedRule::Applies(name, _) => Ok(name),
+            _ => unreachable!("apply only called after evaluate returns Ok(true)"),
+        }
+    }
+}
+
+#[test]
+fn evaluate_all_traced_records_every_rule_and_succeeds() {
+    let rules = vec![
+        TracedRule::Applies("first", 1),
+        TracedRule::Skips("second", 2),
+    ];
+    let engine = RuleEngine::new(rules, None);
+
+    let (result, trace) = engine.evaluate_all_traced(&());
+
+    assert_eq!(result.unwrap(), vec!["first"]);
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[0].rule_name, "first");
+    assert_eq!(trace[0].evaluation, TraceEvaluation::True);
+    assert_eq!(trace[0].applied, Some(true));
+    assert_eq!(trace[1].rule_name, "second");
+    assert_eq!(trace[1].evaluation, TraceEvaluation::False);
+    assert_eq!(trace[1].applied, None);
+}
+
+#[test]
+fn evaluate_all_traced_stops_recording_at_the_erroring_rule() {
+    let rules = vec![
+        TracedRule::Skips("first", 1),
+        TracedRule::Errors("second", 2),
+        TracedRule::Applies("third", 3),
+    ];
+    let engine = RuleEngine::new(rules, None);
+
+    let (result, trace) = engine.evaluate_all_traced(&());
+
+    assert!(result.is_err());
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[1].rule_name, "second");
+    assert_eq!(trace[1].evaluation, TraceEvaluation::Errored);
+}
+
+#[test]
+fn evaluate_first_traced_stops_at_the_first_applicable_rule() {
+    let rules = vec![
+        TracedRule::Skips("first", 1),
+        TracedRule::Applies("second", 2),
+        TracedRule::Applies("third", 3),
+    ];
+    let engine = RuleEngine::new(rules, None);
+
+    let (result, trace) = engine.evaluate_first_traced(&());
+
+    assert_eq!(result.unwrap(), Some("second"));
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[0].rule_name, "first");
+    assert_eq!(trace[1].rule_name, "second");
+    assert_eq!(trace[1].applied, Some(true));
+}